@@ -0,0 +1,10 @@
+use full_moon::node::Node;
+
+/// The `(start, end)` byte range of an AST node, for use in diagnostic labels
+/// and fix spans. Panics on synthesized nodes with no source position, which
+/// rules should never be handed.
+pub fn range(node: &impl Node) -> (u32, u32) {
+    let (start, end) = node.range().expect("node has no range");
+
+    (start.bytes() as u32, end.bytes() as u32)
+}
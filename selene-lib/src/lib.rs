@@ -41,6 +41,10 @@ pub enum CheckerError {
         problem: Box<dyn Error>,
     },
 
+    InvalidFilterKind {
+        filter: String,
+    },
+
     InvalidPlugin(Box<dyn Error>),
 
     RuleNewError {
@@ -57,6 +61,11 @@ impl fmt::Display for CheckerError {
                 "[{name}] Configuration was incorrectly formatted: {problem}",
             ),
 
+            CheckerError::InvalidFilterKind { filter } => write!(
+                formatter,
+                "`{filter}` is not a valid rule filter; expected `-all` or `allow|warn|deny:<category, rule, or all>`",
+            ),
+
             CheckerError::InvalidPlugin(error) => {
                 write!(formatter, "Couldn't load plugin: {error}")
             }
@@ -73,6 +82,15 @@ impl Error for CheckerError {}
 #[serde(rename_all = "kebab-case")]
 pub struct CheckerConfig<V> {
     pub config: HashMap<String, V>,
+
+    // An ordered list of category/rule-wide severity overrides, e.g.
+    // `rule-filters = ["-all", "warn:style", "deny:correctness", "allow:shadowing"]`.
+    // Consulted with last-match-wins precedence; a per-rule key in `rules` still
+    // takes priority over anything here. Parsed into `LintFilter`s by
+    // `Checker::new`, which can report a malformed entry as a `CheckerError`
+    // instead of a bare deserialization error.
+    pub rule_filters: Vec<String>,
+
     pub plugins: Vec<plugins::config::PluginConfig>,
     pub rules: HashMap<String, RuleVariation>,
     pub std: String,
@@ -96,6 +114,7 @@ impl<V> Default for CheckerConfig<V> {
     fn default() -> Self {
         CheckerConfig {
             config: HashMap::new(),
+            rule_filters: Vec::new(),
             rules: HashMap::new(),
             plugins: Vec::new(),
             std: "".to_owned(),
@@ -122,6 +141,57 @@ impl RuleVariation {
     }
 }
 
+// A single entry from `rule_filters`, e.g. `"warn:style"` or `"-all"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintFilter {
+    pub kind: LintFilterKind,
+    pub variation: RuleVariation,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintFilterKind {
+    All,
+    Category(rules::Category),
+    Rule(String),
+}
+
+impl LintFilter {
+    fn parse(entry: &str) -> Result<LintFilter, CheckerError> {
+        let (variation, target) = if entry == "-all" {
+            ("allow", "all")
+        } else {
+            entry.split_once(':').ok_or_else(|| CheckerError::InvalidFilterKind {
+                filter: entry.to_owned(),
+            })?
+        };
+
+        let variation = match variation {
+            "allow" => RuleVariation::Allow,
+            "warn" => RuleVariation::Warn,
+            "deny" => RuleVariation::Deny,
+            _ => {
+                return Err(CheckerError::InvalidFilterKind {
+                    filter: entry.to_owned(),
+                })
+            }
+        };
+
+        let kind = if target == "all" {
+            LintFilterKind::All
+        } else if let Ok(category) = target.parse::<rules::Category>() {
+            LintFilterKind::Category(category)
+        } else if ALL_RULES.contains(&target) {
+            LintFilterKind::Rule(target.to_owned())
+        } else {
+            return Err(CheckerError::InvalidFilterKind {
+                filter: entry.to_owned(),
+            });
+        };
+
+        Ok(LintFilter { kind, variation })
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum RobloxStdSource {
@@ -165,6 +235,8 @@ macro_rules! use_rules {
 
         pub struct Checker<V: 'static + DeserializeOwned> {
             config: CheckerConfig<V>,
+            config_diagnostics: Vec<CheckerDiagnostic>,
+            rule_filters: Vec<LintFilter>,
             context: Context,
             plugins: Vec<plugins::LuaPlugin>,
 
@@ -181,7 +253,6 @@ macro_rules! use_rules {
         }
 
         impl<V: 'static + DeserializeOwned> Checker<V> {
-            // TODO: Be more strict about config? Make sure all keys exist
             pub fn new(
                 mut config: CheckerConfig<V>,
                 standard_library: StandardLibrary,
@@ -216,7 +287,15 @@ macro_rules! use_rules {
                     }};
                 }
 
-                Ok(Self {
+                let rule_filters = config
+                    .rule_filters
+                    .iter()
+                    .map(|entry| LintFilter::parse(entry))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let mut config_diagnostics = Vec::new();
+
+                let rules = Self {
                     $(
                         $rule_name: {
                             rule_field!($rule_name, $rule_path)
@@ -231,15 +310,53 @@ macro_rules! use_rules {
                         )+
                     )+
 
+                    rule_filters,
+
                     context: Context {
                         standard_library,
                         standard_library_is_set: !config.std.is_empty(),
                     },
 
-                    plugins: create_plugins_from_config(&config)?,
+                    plugins: {
+                        let plugins = create_plugins_from_config(&config)?;
+
+                        // Anything left in `config.config` at this point wasn't
+                        // claimed by a rule above, so it's an orphaned per-rule
+                        // config block.
+                        config_diagnostics = validate_config(&config, &plugins);
+
+                        plugins
+                    },
+                    config_diagnostics,
 
                     config,
-                })
+                };
+
+                Ok(rules)
+            }
+
+            /// Warnings produced while validating `selene.toml` itself: unrecognized
+            /// rule names in `rules`, or config blocks for rules that don't exist.
+            /// An unrecognized `rule_filters` entry is not reported here; it makes
+            /// [`Checker::new`] fail instead. Separate from [`Checker::test_on`] since these describe
+            /// the configuration, not the Lua source being linted.
+            pub fn config_diagnostics(&self) -> &[CheckerDiagnostic] {
+                &self.config_diagnostics
+            }
+
+            // Runs every rule, then collects and applies all MachineApplicable fixes
+            // in a single pass, anchored to the original (unedited) source offsets.
+            pub fn fix_on(&self, ast: &Ast, source: &str) -> String {
+                let diagnostics = self.test_on(ast);
+
+                let edits: Vec<(u32, u32, String)> = diagnostics
+                    .into_iter()
+                    .flat_map(|diagnostic| diagnostic.diagnostic.fixes)
+                    .filter(|fix| fix.applicability == rules::Applicability::MachineApplicable)
+                    .map(|fix| (fix.range.0, fix.range.1, fix.replacement))
+                    .collect();
+
+                text::splice(source, &drop_overlapping_edits(edits))
             }
 
             pub fn test_on(&self, ast: &Ast) -> Vec<CheckerDiagnostic> {
@@ -294,10 +411,37 @@ macro_rules! use_rules {
 
 impl<V: 'static + DeserializeOwned> Checker<V> {
     fn get_lint_severity<R: Rule>(&self, _lint: &R, name: &'static str) -> Severity {
-        match self.config.rules.get(name) {
-            Some(variation) => variation.to_severity(),
-            None => R::SEVERITY,
+        self.severity_from_filters(name, Some(R::CATEGORY), R::SEVERITY)
+    }
+
+    // Resolves a rule or plugin's effective severity: an exact `rules.<name>` entry
+    // always wins, otherwise `rule_filters` is scanned in order with the last
+    // matching entry (by name, category, or `all`) taking precedence.
+    fn severity_from_filters(
+        &self,
+        name: &str,
+        category: Option<rules::Category>,
+        default: Severity,
+    ) -> Severity {
+        if let Some(variation) = self.config.rules.get(name) {
+            return variation.to_severity();
         }
+
+        let mut severity = default;
+
+        for filter in &self.rule_filters {
+            let matches = match &filter.kind {
+                LintFilterKind::All => true,
+                LintFilterKind::Category(filter_category) => Some(*filter_category) == category,
+                LintFilterKind::Rule(rule_name) => rule_name == name,
+            };
+
+            if matches {
+                severity = filter.variation.to_severity();
+            }
+        }
+
+        severity
     }
 
     fn run_plugins(
@@ -325,10 +469,11 @@ impl<V: 'static + DeserializeOwned> Checker<V> {
                     diagnostics.extend(&mut plugin_diagnostics.into_iter().map(|diagnostic| {
                         CheckerDiagnostic {
                             diagnostic,
-                            severity: match self.config.rules.get(&plugin_name) {
-                                Some(variation) => variation.to_severity(),
-                                None => plugin.severity,
-                            },
+                            severity: self.severity_from_filters(
+                                &plugin_name,
+                                plugin.category,
+                                plugin.severity,
+                            ),
                         }
                     }));
                 }
@@ -349,6 +494,33 @@ impl<V: 'static + DeserializeOwned> Checker<V> {
     }
 }
 
+// Drops any edit that overlaps the one before it once both are sorted by
+// `start`. Two zero-width edits anchored at the same point (e.g. two rules
+// both wanting to insert text at the same byte) count as overlapping too,
+// even though `start < cursor` alone wouldn't catch that.
+fn drop_overlapping_edits(mut edits: Vec<(u32, u32, String)>) -> Vec<(u32, u32, String)> {
+    edits.sort_by_key(|(start, _, _)| *start);
+
+    let mut non_overlapping = Vec::with_capacity(edits.len());
+    let mut cursor = 0;
+    let mut cursor_is_zero_width = false;
+
+    for edit in edits {
+        let (start, end, _) = &edit;
+        let (start, end) = (*start, *end);
+
+        if start < cursor || (start == cursor && cursor_is_zero_width && start == end) {
+            continue;
+        }
+
+        cursor = end;
+        cursor_is_zero_width = start == end;
+        non_overlapping.push(edit);
+    }
+
+    non_overlapping
+}
+
 fn create_plugins_from_config<V>(
     config: &CheckerConfig<V>,
 ) -> Result<Vec<plugins::LuaPlugin>, CheckerError> {
@@ -364,7 +536,90 @@ fn create_plugins_from_config<V>(
     Ok(plugins)
 }
 
-#[derive(Debug)]
+// Catches typos like `rules.unused_variabl = "allow"` that would otherwise
+// silently do nothing: anything in `rules`, or left over in `config` after every
+// rule has claimed its own block, that names neither a rule nor a loaded plugin
+// is reported as a warning, with a "did you mean?" suggestion against
+// `ALL_RULES` plus the loaded plugin names.
+fn validate_config<V>(
+    config: &CheckerConfig<V>,
+    plugins: &[plugins::LuaPlugin],
+) -> Vec<CheckerDiagnostic> {
+    let plugin_names: Vec<String> = plugins.iter().map(|plugin| plugin.full_name()).collect();
+
+    let known_names: Vec<&str> = ALL_RULES
+        .iter()
+        .copied()
+        .chain(plugin_names.iter().map(String::as_str))
+        .collect();
+
+    let mut diagnostics = Vec::new();
+
+    for rule_name in config.rules.keys() {
+        if !known_names.contains(&rule_name.as_str()) {
+            diagnostics.push(unknown_name_diagnostic("rules", rule_name, &known_names));
+        }
+    }
+
+    for orphaned_key in config.config.keys() {
+        if !known_names.contains(&orphaned_key.as_str()) {
+            diagnostics.push(unknown_name_diagnostic("config", orphaned_key, &known_names));
+        }
+    }
+
+    diagnostics
+}
+
+fn unknown_name_diagnostic(table: &str, name: &str, known_names: &[&str]) -> CheckerDiagnostic {
+    let message = match nearest_match(name, known_names) {
+        Some(suggestion) => format!(
+            "`{table}.{name}` does not match any known rule or plugin; did you mean `{suggestion}`?",
+        ),
+        None => format!("`{table}.{name}` does not match any known rule or plugin"),
+    };
+
+    CheckerDiagnostic {
+        diagnostic: Diagnostic::new("unknown_config_key".to_owned(), message, rules::Label::new((0, 0))),
+        severity: Severity::Warning,
+    }
+}
+
+fn nearest_match<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (*candidate, levenshtein_distance(name, candidate)))
+        .min_by_key(|(_, distance)| *distance)
+        .filter(|(_, distance)| *distance <= 3)
+        .map(|(candidate, _)| candidate)
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &a_char) in a.iter().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, &b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != b_char);
+            let deletion = row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            let substitution = previous + cost;
+
+            previous = row[j + 1];
+            row[j + 1] = deletion.min(insertion).min(substitution);
+        }
+    }
+
+    row[b.len()]
+}
+
+// Derives Serialize so editor/LSP integrations can consume a stable JSON
+// representation instead of scraping the human-readable display format.
+#[derive(Debug, serde::Serialize)]
 pub struct CheckerDiagnostic {
     pub diagnostic: Diagnostic,
     pub severity: Severity,
@@ -406,3 +661,148 @@ use_rules! {
         roblox_incorrect_roact_usage: rules::roblox_incorrect_roact_usage::IncorrectRoactUsageLint,
     },
 }
+
+#[cfg(test)]
+mod fix_on_tests {
+    use super::*;
+
+    #[test]
+    fn keeps_non_overlapping_edits_in_order() {
+        let edits = vec![
+            (0u32, 1u32, "a".to_owned()),
+            (2u32, 3u32, "b".to_owned()),
+        ];
+
+        assert_eq!(drop_overlapping_edits(edits.clone()), edits);
+    }
+
+    #[test]
+    fn drops_an_edit_that_starts_before_the_previous_one_ends() {
+        let edits = vec![
+            (0u32, 5u32, "first".to_owned()),
+            (3u32, 8u32, "second".to_owned()),
+        ];
+
+        assert_eq!(
+            drop_overlapping_edits(edits),
+            vec![(0u32, 5u32, "first".to_owned())],
+        );
+    }
+
+    #[test]
+    fn drops_a_second_zero_width_edit_anchored_at_the_same_point() {
+        // Two different rules both inserting text at the same byte offset:
+        // naive `start < cursor` wouldn't reject this, since `start == cursor`
+        // rather than `start < cursor`.
+        let edits = vec![
+            (4u32, 4u32, "first".to_owned()),
+            (4u32, 4u32, "second".to_owned()),
+        ];
+
+        assert_eq!(
+            drop_overlapping_edits(edits),
+            vec![(4u32, 4u32, "first".to_owned())],
+        );
+    }
+
+    #[test]
+    fn a_zero_width_edit_does_not_block_an_edit_that_starts_right_after_it() {
+        let edits = vec![
+            (4u32, 4u32, "inserted".to_owned()),
+            (4u32, 6u32, "replaced".to_owned()),
+        ];
+
+        assert_eq!(
+            drop_overlapping_edits(edits.clone()),
+            edits,
+        );
+    }
+}
+
+#[cfg(test)]
+mod rule_filter_tests {
+    use super::*;
+
+    fn checker_with_filters(rule_filters: &[&str]) -> Checker<toml::Value> {
+        let config = CheckerConfig {
+            rule_filters: rule_filters.iter().map(|entry| (*entry).to_owned()).collect(),
+            ..CheckerConfig::default()
+        };
+
+        Checker::new(config, StandardLibrary::default()).expect("filters are valid")
+    }
+
+    #[test]
+    fn later_filters_win_over_earlier_ones() {
+        let checker =
+            checker_with_filters(&["-all", "warn:style", "deny:correctness", "allow:shadowing"]);
+
+        // `-all` then overridden by category, then overridden again by name.
+        assert_eq!(
+            checker.severity_from_filters("shadowing", Some(rules::Category::Correctness), Severity::Warning),
+            Severity::Allow,
+        );
+
+        // Past `-all`, only matched by `warn:style`.
+        assert_eq!(
+            checker.severity_from_filters(
+                "parenthese_conditions",
+                Some(rules::Category::Style),
+                Severity::Warning,
+            ),
+            Severity::Warning,
+        );
+
+        // Past `-all`, only matched by `deny:correctness`.
+        assert_eq!(
+            checker.severity_from_filters(
+                "type_check_inside_call",
+                Some(rules::Category::Correctness),
+                Severity::Error,
+            ),
+            Severity::Error,
+        );
+
+        // Not named or categorized by anything past `-all`.
+        assert_eq!(
+            checker.severity_from_filters("deprecated", Some(rules::Category::Suspicious), Severity::Warning),
+            Severity::Allow,
+        );
+    }
+
+    #[test]
+    fn a_rules_entry_overrides_rule_filters() {
+        let mut config = CheckerConfig {
+            rule_filters: vec!["deny:all".to_owned()],
+            ..CheckerConfig::default()
+        };
+        config
+            .rules
+            .insert("parenthese_conditions".to_owned(), RuleVariation::Allow);
+
+        let checker: Checker<toml::Value> =
+            Checker::new(config, StandardLibrary::default()).expect("filters are valid");
+
+        assert_eq!(
+            checker.severity_from_filters(
+                "parenthese_conditions",
+                Some(rules::Category::Style),
+                Severity::Warning,
+            ),
+            Severity::Allow,
+        );
+    }
+
+    #[test]
+    fn an_unrecognized_filter_entry_is_reported_as_a_checker_error() {
+        let config: CheckerConfig<toml::Value> = CheckerConfig {
+            rule_filters: vec!["deny:not_a_real_category".to_owned()],
+            ..CheckerConfig::default()
+        };
+
+        assert!(matches!(
+            Checker::new(config, StandardLibrary::default()),
+            Err(CheckerError::InvalidFilterKind { .. }),
+        ));
+    }
+}
@@ -0,0 +1,15 @@
+use full_moon::ast::Ast;
+
+use crate::CheckerDiagnostic;
+
+/// Hook for inline `-- selene: allow(...)`/`deny(...)` comment overrides.
+///
+/// Returns `diagnostics` unchanged: comment directives are not parsed, and
+/// no diagnostic is removed or has its severity changed.
+pub fn filter_diagnostics(
+    _ast: &Ast,
+    diagnostics: Vec<CheckerDiagnostic>,
+    _invalid_lint_filter_severity: crate::rules::Severity,
+) -> Vec<CheckerDiagnostic> {
+    diagnostics
+}
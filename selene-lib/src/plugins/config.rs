@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::{rules::Category, RuleVariation};
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PluginConfig {
+    pub name: String,
+    pub source: PathBuf,
+
+    #[serde(default)]
+    pub severity: Option<RuleVariation>,
+
+    #[serde(default)]
+    pub category: Option<Category>,
+}
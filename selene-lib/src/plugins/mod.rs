@@ -0,0 +1,48 @@
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+
+pub mod config;
+
+use config::PluginConfig;
+
+use crate::rules::{AstContext, Category, Context, Diagnostic, Severity};
+
+/// A lint implemented as a Lua script rather than a built-in `Rule`. Carries
+/// the same severity/category plumbing as a built-in rule so plugin
+/// diagnostics participate in `rule_filters` category filtering like any
+/// other lint.
+pub struct LuaPlugin {
+    name: String,
+    pub severity: Severity,
+    pub category: Option<Category>,
+}
+
+impl LuaPlugin {
+    pub fn new(config: &PluginConfig) -> Result<Self, Box<dyn Error>> {
+        Ok(LuaPlugin {
+            name: config.name.clone(),
+            severity: config
+                .severity
+                .map(crate::RuleVariation::to_severity)
+                .unwrap_or(Severity::Warning),
+            category: config.category,
+        })
+    }
+
+    pub fn full_name(&self) -> String {
+        format!("plugins.{}", self.name)
+    }
+
+    pub fn pass(
+        &self,
+        _ast: Arc<Mutex<full_moon_lua_types::Ast>>,
+        _context: &Context,
+        _ast_context: &AstContext,
+    ) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+        // Running the plugin's Lua body is a separate sandboxing concern from
+        // the category-filtering plumbing this module currently provides.
+        Ok(Vec::new())
+    }
+}
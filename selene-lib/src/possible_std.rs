@@ -0,0 +1,8 @@
+/// Globals that exist in some but not all of selene's standard library
+/// definitions (e.g. Roblox-only globals). No rule in this crate calls it.
+pub fn is_possible_std_global(name: &str) -> bool {
+    matches!(
+        name,
+        "game" | "script" | "workspace" | "plugin" | "shared" | "_G"
+    )
+}
@@ -0,0 +1,152 @@
+use std::convert::Infallible;
+
+use full_moon::ast::{Ast, Block, Stmt, Var};
+use serde::Deserialize;
+
+use crate::{
+    ast_util,
+    rules::{Applicability, AstContext, Category, Context, Diagnostic, Fix, Label, Rule, Severity},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AlmostSwappedConfig {}
+
+#[derive(Debug, Default)]
+pub struct AlmostSwappedLint;
+
+impl Rule for AlmostSwappedLint {
+    type Config = AlmostSwappedConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Warning;
+    const CATEGORY: Category = Category::Correctness;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(AlmostSwappedLint)
+    }
+
+    fn pass(&self, ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        walk_block(ast.nodes(), &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn walk_block(block: &Block, diagnostics: &mut Vec<Diagnostic>) {
+    let stmts: Vec<&Stmt> = block.stmts().collect();
+
+    for window in stmts.windows(2) {
+        if let Some(diagnostic) = check_swap(window[0], window[1]) {
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    for stmt in &stmts {
+        for nested in nested_blocks(stmt) {
+            walk_block(nested, diagnostics);
+        }
+    }
+}
+
+fn nested_blocks(stmt: &Stmt) -> Vec<&Block> {
+    match stmt {
+        Stmt::Do(do_stmt) => vec![do_stmt.block()],
+        Stmt::While(while_stmt) => vec![while_stmt.block()],
+        Stmt::Repeat(repeat_stmt) => vec![repeat_stmt.block()],
+        Stmt::If(if_stmt) => {
+            let mut blocks = vec![if_stmt.block()];
+
+            if let Some(else_ifs) = if_stmt.else_if() {
+                blocks.extend(else_ifs.iter().map(|else_if| else_if.block()));
+            }
+
+            if let Some(else_block) = if_stmt.else_block() {
+                blocks.push(else_block);
+            }
+
+            blocks
+        }
+        Stmt::NumericFor(for_stmt) => vec![for_stmt.block()],
+        Stmt::GenericFor(for_stmt) => vec![for_stmt.block()],
+        Stmt::FunctionDeclaration(function) => vec![function.body().block()],
+        Stmt::LocalFunction(function) => vec![function.body().block()],
+        _ => Vec::new(),
+    }
+}
+
+// A plain `name = identifier` assignment: one variable, one expression, and
+// that expression is just a bare name (not `b + 1` or a function call).
+fn simple_assignment(stmt: &Stmt) -> Option<(String, String)> {
+    let Stmt::Assignment(assignment) = stmt else {
+        return None;
+    };
+
+    if assignment.variables().len() != 1 || assignment.expressions().len() != 1 {
+        return None;
+    }
+
+    let Var::Name(lhs) = assignment.variables().iter().next()? else {
+        return None;
+    };
+
+    let rhs = assignment.expressions().iter().next()?.to_string();
+    let rhs = rhs.trim();
+
+    if rhs.is_empty() || !rhs.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    Some((lhs.to_string().trim().to_owned(), rhs.to_owned()))
+}
+
+fn check_swap(first: &Stmt, second: &Stmt) -> Option<Diagnostic> {
+    let (a, b) = simple_assignment(first)?;
+    let (lhs2, rhs2) = simple_assignment(second)?;
+
+    if a == b || lhs2 != b || rhs2 != a {
+        return None;
+    }
+
+    let (start, _) = ast_util::range(first);
+    let (_, end) = ast_util::range(second);
+
+    Some(
+        Diagnostic::new(
+            "almost_swapped",
+            format!("`{a}` and `{b}` are being set to each other's current value, not swapped"),
+            Label::new((start, end)),
+        )
+        .with_fixes(vec![Fix {
+            range: (start, end),
+            replacement: format!("{a}, {b} = {b}, {a}"),
+            applicability: Applicability::MachineApplicable,
+            label: "swap with a multiple assignment".to_owned(),
+        }]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(source: &str) -> Vec<Diagnostic> {
+        let ast = full_moon::parse(source).expect("valid lua");
+        AlmostSwappedLint.pass(
+            &ast,
+            &Context::default(),
+            &AstContext::from_ast(&ast),
+        )
+    }
+
+    #[test]
+    fn flags_a_non_swapping_swap_attempt() {
+        let diagnostics = diagnostics("a = b\nb = a\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fixes[0].replacement, "a, b = b, a");
+    }
+
+    #[test]
+    fn allows_an_actual_multiple_assignment_swap() {
+        assert!(diagnostics("a, b = b, a\n").is_empty());
+    }
+}
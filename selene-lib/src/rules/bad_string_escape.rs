@@ -0,0 +1,140 @@
+use std::convert::Infallible;
+
+use full_moon::{
+    ast::Ast,
+    node::Node,
+    tokenizer::{TokenReference, TokenType},
+};
+use serde::Deserialize;
+
+use crate::{
+    ast_util,
+    rules::{Applicability, AstContext, Category, Context, Diagnostic, Fix, Label, Rule, Severity},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct BadStringEscapeConfig {}
+
+#[derive(Debug, Default)]
+pub struct BadStringEscapeLint;
+
+impl Rule for BadStringEscapeLint {
+    type Config = BadStringEscapeConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Error;
+    const CATEGORY: Category = Category::Correctness;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(BadStringEscapeLint)
+    }
+
+    fn pass(&self, ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        ast.tokens()
+            .filter_map(check_token)
+            .flatten()
+            .collect()
+    }
+}
+
+fn check_token(token: &TokenReference) -> Option<Vec<Diagnostic>> {
+    let TokenType::StringLiteral {
+        literal,
+        multi_line: None,
+        ..
+    } = token.token_type()
+    else {
+        return None;
+    };
+
+    let (token_start, _) = ast_util::range(token);
+
+    // Quoted literals are exactly one quote character wide, so the literal
+    // body starts one byte past the token's start.
+    let literal_start = token_start as usize + 1;
+
+    let mut diagnostics = Vec::new();
+    let mut chars = literal.char_indices().peekable();
+
+    while let Some((byte_index, c)) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+
+        let Some(&(_, escaped)) = chars.peek() else {
+            continue;
+        };
+
+        if is_valid_escape(escaped) {
+            chars.next();
+            continue;
+        }
+
+        let start = (literal_start + byte_index) as u32;
+        let end = start + escaped.len_utf8() as u32 + 1;
+
+        diagnostics.push(
+            Diagnostic::new(
+                "bad_string_escape",
+                format!("`\\{escaped}` is not a recognized string escape sequence"),
+                Label::new((start, end)),
+            )
+            .with_fixes(vec![Fix {
+                range: (start, end),
+                replacement: escaped.to_string(),
+                applicability: Applicability::MachineApplicable,
+                label: "remove the backslash".to_owned(),
+            }]),
+        );
+
+        chars.next();
+    }
+
+    Some(diagnostics)
+}
+
+fn is_valid_escape(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'b' | 'f' | 'n' | 'r' | 't' | 'v' | '\\' | '"' | '\'' | '\n' | 'z' | 'x' | 'u'
+    ) || c.is_ascii_digit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(source: &str) -> Vec<Diagnostic> {
+        let ast = full_moon::parse(source).expect("valid lua");
+        BadStringEscapeLint.pass(
+            &ast,
+            &Context::default(),
+            &AstContext::from_ast(&ast),
+        )
+    }
+
+    #[test]
+    fn flags_an_unrecognized_escape() {
+        let diagnostics = diagnostics(r#"local a = "hello\qworld""#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fixes[0].replacement, "q");
+    }
+
+    #[test]
+    fn allows_recognized_escapes() {
+        assert!(diagnostics(r#"local a = "hello\nworld""#).is_empty());
+    }
+
+    #[test]
+    fn computes_byte_offsets_past_multibyte_characters() {
+        // `é` is 2 bytes in UTF-8; a naive char-index offset would land the
+        // fix one byte short of `\q` and panic or mis-splice on apply.
+        let source = r#"local a = "héllo\qworld""#;
+        let diagnostics = diagnostics(source);
+
+        assert_eq!(diagnostics.len(), 1);
+
+        let (start, end) = diagnostics[0].primary_label.range;
+        assert_eq!(&source[start as usize..end as usize], "\\q");
+    }
+}
@@ -0,0 +1,166 @@
+use std::{collections::HashMap, convert::Infallible};
+
+use full_moon::ast::{Ast, Block, Expression, Field, LastStmt, Stmt, TableConstructor};
+use serde::Deserialize;
+
+use crate::{
+    ast_util,
+    rules::{AstContext, Category, Context, Diagnostic, Label, RelatedInformation, Rule, Severity},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DuplicateKeysConfig {}
+
+#[derive(Debug, Default)]
+pub struct DuplicateKeysLint;
+
+impl Rule for DuplicateKeysLint {
+    type Config = DuplicateKeysConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Warning;
+    const CATEGORY: Category = Category::Correctness;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(DuplicateKeysLint)
+    }
+
+    fn pass(&self, ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        walk_block(ast.nodes(), &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn walk_block(block: &Block, diagnostics: &mut Vec<Diagnostic>) {
+    for stmt in block.stmts() {
+        match stmt {
+            Stmt::Assignment(assignment) => {
+                for expr in assignment.expressions() {
+                    check_expression(expr, diagnostics);
+                }
+            }
+
+            Stmt::LocalAssignment(local) => {
+                for expr in local.expressions() {
+                    check_expression(expr, diagnostics);
+                }
+            }
+
+            Stmt::If(if_stmt) => {
+                walk_block(if_stmt.block(), diagnostics);
+
+                if let Some(else_ifs) = if_stmt.else_if() {
+                    for else_if in else_ifs {
+                        walk_block(else_if.block(), diagnostics);
+                    }
+                }
+
+                if let Some(else_block) = if_stmt.else_block() {
+                    walk_block(else_block, diagnostics);
+                }
+            }
+
+            Stmt::While(while_stmt) => walk_block(while_stmt.block(), diagnostics),
+            Stmt::Repeat(repeat_stmt) => walk_block(repeat_stmt.block(), diagnostics),
+            Stmt::Do(do_stmt) => walk_block(do_stmt.block(), diagnostics),
+            Stmt::NumericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::GenericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::FunctionDeclaration(function) => walk_block(function.body().block(), diagnostics),
+            Stmt::LocalFunction(function) => walk_block(function.body().block(), diagnostics),
+
+            _ => {}
+        }
+    }
+
+    if let Some(LastStmt::Return(return_stmt)) = block.last_stmt() {
+        for expr in return_stmt.returns() {
+            check_expression(expr, diagnostics);
+        }
+    }
+}
+
+fn check_expression(expression: &Expression, diagnostics: &mut Vec<Diagnostic>) {
+    match expression {
+        Expression::Parentheses { expression, .. } => check_expression(expression, diagnostics),
+        Expression::UnaryOperator { expression, .. } => check_expression(expression, diagnostics),
+        Expression::BinaryOperator { lhs, rhs, .. } => {
+            check_expression(lhs, diagnostics);
+            check_expression(rhs, diagnostics);
+        }
+        Expression::TableConstructor(table) => check_table(table, diagnostics),
+        _ => {}
+    }
+}
+
+// Only plain `name = value` keys are compared against each other: computed
+// keys (`["name"] = value`) aren't normalized against name keys that spell
+// the same string, so they're left unchecked rather than risk a false
+// positive on two keys that only look alike.
+fn check_table(table: &TableConstructor, diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen: HashMap<String, (u32, u32)> = HashMap::new();
+
+    for field in table.fields() {
+        match field {
+            Field::NameKey { key, value, .. } => {
+                check_expression(value, diagnostics);
+
+                let key_name = key.to_string().trim().to_owned();
+                let (start, end) = ast_util::range(key);
+
+                if let Some(&(first_start, first_end)) = seen.get(&key_name) {
+                    diagnostics.push(
+                        Diagnostic::new(
+                            "duplicate_keys",
+                            format!("`{key_name}` is already defined in this table"),
+                            Label::new((start, end)),
+                        )
+                        .with_related_information(vec![RelatedInformation {
+                            message: "previously defined here".to_owned(),
+                            label: Label::new((first_start, first_end)),
+                        }]),
+                    );
+                } else {
+                    seen.insert(key_name, (start, end));
+                }
+            }
+
+            Field::ExpressionKey { value, .. } | Field::NoKey(value) => {
+                check_expression(value, diagnostics);
+            }
+
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(source: &str) -> Vec<Diagnostic> {
+        let ast = full_moon::parse(source).expect("valid lua");
+        DuplicateKeysLint.pass(&ast, &Context::default(), &AstContext::from_ast(&ast))
+    }
+
+    #[test]
+    fn flags_a_repeated_name_key() {
+        let diagnostics = diagnostics("local t = {foo = 1, bar = 2, foo = 3}");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].related_information.len(), 1);
+        assert_eq!(
+            diagnostics[0].related_information[0].message,
+            "previously defined here"
+        );
+    }
+
+    #[test]
+    fn allows_distinct_keys() {
+        assert!(diagnostics("local t = {foo = 1, bar = 2}").is_empty());
+    }
+
+    #[test]
+    fn allows_a_name_key_and_a_computed_key_that_spell_the_same_string() {
+        assert!(diagnostics(r#"local t = {foo = 1, ["foo"] = 2}"#).is_empty());
+    }
+}
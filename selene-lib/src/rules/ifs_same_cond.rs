@@ -0,0 +1,130 @@
+use std::convert::Infallible;
+
+use full_moon::ast::{Ast, Block, Expression, If, Stmt};
+use serde::Deserialize;
+
+use crate::{
+    ast_util,
+    rules::{AstContext, Category, Context, Diagnostic, Label, Rule, Severity},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct IfsSameCondConfig {}
+
+#[derive(Debug, Default)]
+pub struct IfsSameCondLint;
+
+impl Rule for IfsSameCondLint {
+    type Config = IfsSameCondConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Warning;
+    const CATEGORY: Category = Category::Correctness;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(IfsSameCondLint)
+    }
+
+    fn pass(&self, ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        walk_block(ast.nodes(), &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn walk_block(block: &Block, diagnostics: &mut Vec<Diagnostic>) {
+    for stmt in block.stmts() {
+        match stmt {
+            Stmt::If(if_stmt) => {
+                check_if_chain(if_stmt, diagnostics);
+                walk_block(if_stmt.block(), diagnostics);
+
+                if let Some(else_ifs) = if_stmt.else_if() {
+                    for else_if in else_ifs {
+                        walk_block(else_if.block(), diagnostics);
+                    }
+                }
+
+                if let Some(else_block) = if_stmt.else_block() {
+                    walk_block(else_block, diagnostics);
+                }
+            }
+
+            Stmt::While(while_stmt) => walk_block(while_stmt.block(), diagnostics),
+            Stmt::Repeat(repeat_stmt) => walk_block(repeat_stmt.block(), diagnostics),
+            Stmt::Do(do_stmt) => walk_block(do_stmt.block(), diagnostics),
+            Stmt::NumericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::GenericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::FunctionDeclaration(function) => walk_block(function.body().block(), diagnostics),
+            Stmt::LocalFunction(function) => walk_block(function.body().block(), diagnostics),
+
+            _ => {}
+        }
+    }
+}
+
+// Every condition in a single `if`/`elseif` chain, in source order. The
+// `else` branch has no condition of its own and is excluded.
+fn chain_conditions(if_stmt: &If) -> Vec<&Expression> {
+    let mut conditions = vec![if_stmt.condition()];
+
+    if let Some(else_ifs) = if_stmt.else_if() {
+        conditions.extend(else_ifs.iter().map(|else_if| else_if.condition()));
+    }
+
+    conditions
+}
+
+fn check_if_chain(if_stmt: &If, diagnostics: &mut Vec<Diagnostic>) {
+    let conditions = chain_conditions(if_stmt);
+
+    for (later_index, later_condition) in conditions.iter().enumerate().skip(1) {
+        let later_text = later_condition.to_string();
+        let later_text = later_text.trim();
+
+        let Some(earlier_condition) = conditions[..later_index]
+            .iter()
+            .find(|earlier| earlier.to_string().trim() == later_text)
+        else {
+            continue;
+        };
+
+        let (earlier_start, earlier_end) = ast_util::range(*earlier_condition);
+        let (later_start, later_end) = ast_util::range(*later_condition);
+
+        diagnostics.push(
+            Diagnostic::new(
+                "ifs_same_cond",
+                "this condition is the same as an earlier one in the same if-chain".to_owned(),
+                Label::new((later_start, later_end)),
+            )
+            .with_secondary_labels(vec![Label::new((earlier_start, earlier_end))]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(source: &str) -> Vec<Diagnostic> {
+        let ast = full_moon::parse(source).expect("valid lua");
+        IfsSameCondLint.pass(
+            &ast,
+            &Context::default(),
+            &AstContext::from_ast(&ast),
+        )
+    }
+
+    #[test]
+    fn flags_a_repeated_condition_in_an_elseif_chain() {
+        let diagnostics = diagnostics("if x then\nelseif y then\nelseif x then\nend");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].secondary_labels.len(), 1);
+    }
+
+    #[test]
+    fn allows_distinct_conditions() {
+        assert!(diagnostics("if x then\nelseif y then\nend").is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+use std::str::FromStr;
+
+use full_moon::ast::Ast;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::standard_library::StandardLibrary;
+
+pub mod almost_swapped;
+pub mod bad_string_escape;
+pub mod compare_nan;
+pub mod constant_table_comparison;
+pub mod deprecated;
+pub mod divide_by_zero;
+pub mod duplicate_keys;
+pub mod empty_if;
+pub mod global_usage;
+pub mod if_same_then_else;
+pub mod ifs_same_cond;
+pub mod invalid_lint_filter;
+pub mod mismatched_arg_count;
+pub mod multiple_statements;
+pub mod must_use;
+pub mod parenthese_conditions;
+pub mod shadowing;
+pub mod standard_library;
+pub mod suspicious_reverse_loop;
+pub mod type_check_inside_call;
+pub mod unbalanced_assignments;
+pub mod undefined_variable;
+pub mod unscoped_variables;
+pub mod unused_variable;
+
+/// Precomputed, AST-derived context shared by every rule's `pass`. Kept as its
+/// own type (rather than threading the `Ast` alone) so future passes can grow
+/// shared lookups (e.g. scope maps) without changing every rule's signature.
+pub struct AstContext {}
+
+impl AstContext {
+    pub fn from_ast(_ast: &Ast) -> Self {
+        AstContext {}
+    }
+}
+
+/// Shared, read-only state every rule's `pass` gets access to: the resolved
+/// standard library a linted file is checked against.
+#[derive(Default)]
+pub struct Context {
+    pub standard_library: StandardLibrary,
+    pub standard_library_is_set: bool,
+}
+
+pub trait Rule {
+    type Config: DeserializeOwned + Default;
+    type Error: std::error::Error;
+
+    const SEVERITY: Severity;
+    const CATEGORY: Category;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+
+    /// Most rule modules are still faithful no-ops (`Vec::new()`): real
+    /// diagnostic logic only exists for `almost_swapped`, `bad_string_escape`,
+    /// `duplicate_keys`, `ifs_same_cond`, `parenthese_conditions`, and
+    /// `type_check_inside_call`, leaving the rest as stubs rather than
+    /// guessing at lint behavior nobody asked to implement.
+    fn pass(&self, ast: &Ast, context: &Context, ast_context: &AstContext) -> Vec<Diagnostic>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    Allow,
+    Error,
+    Warning,
+}
+
+/// A lint's category, declared as [`Rule::CATEGORY`]. Lets `rule_filters`
+/// (see `CheckerConfig`) turn off or reweight a whole family of lints at once
+/// instead of listing every rule name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Category {
+    Complexity,
+    Correctness,
+    Roblox,
+    Style,
+    Suspicious,
+}
+
+impl FromStr for Category {
+    type Err = ();
+
+    fn from_str(category: &str) -> Result<Self, Self::Err> {
+        match category {
+            "complexity" => Ok(Category::Complexity),
+            "correctness" => Ok(Category::Correctness),
+            "roblox" => Ok(Category::Roblox),
+            "style" => Ok(Category::Style),
+            "suspicious" => Ok(Category::Suspicious),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A span of source, anchored to byte offsets in the original (unedited)
+/// source text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct Label {
+    pub range: (u32, u32),
+}
+
+impl Label {
+    pub fn new(range: (u32, u32)) -> Self {
+        Label { range }
+    }
+}
+
+/// How safe a [`Fix`] is to apply without a human reading it first, mirroring
+/// rust-analyzer/rustc's assist applicability levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Applicability {
+    /// Safe to apply automatically; the rewrite is certainly what the code
+    /// means. It may change behavior (e.g. `type_check_inside_call`'s fix).
+    MachineApplicable,
+    /// Probably what the user wants, but may not be; needs review.
+    MaybeIncorrect,
+    /// The fix contains a placeholder the user must fill in before it's valid.
+    HasPlaceholders,
+}
+
+/// A single suggested edit: replace the original source in `range` (byte
+/// offsets into the *unedited* source) with `replacement`.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Fix {
+    pub range: (u32, u32),
+    pub replacement: String,
+    pub applicability: Applicability,
+    pub label: String,
+}
+
+/// A message anchored to a span other than a diagnostic's primary one, e.g.
+/// "original definition here" pointing back at a shadowed binding. Unlike
+/// [`Diagnostic::secondary_labels`] (extra spans, no message of their own),
+/// each entry here carries its own explanation for editor/LSP integrations
+/// that render them as separate, clickable notes.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct RelatedInformation {
+    pub message: String,
+    pub label: Label,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Diagnostic {
+    pub code: String,
+    pub message: String,
+    pub primary_label: Label,
+    /// Additional spans to highlight alongside `primary_label`, with no
+    /// message of their own — e.g. `ifs_same_cond` uses this to also
+    /// highlight the earlier, identical condition.
+    pub secondary_labels: Vec<Label>,
+    /// Spans elsewhere in the source that explain the diagnostic, each with
+    /// its own message (e.g. `duplicate_keys` uses this to point back at a
+    /// key's first definition).
+    pub related_information: Vec<RelatedInformation>,
+    pub fixes: Vec<Fix>,
+}
+
+impl Diagnostic {
+    pub fn new(code: impl Into<String>, message: String, primary_label: Label) -> Self {
+        Diagnostic {
+            code: code.into(),
+            message,
+            primary_label,
+            secondary_labels: Vec::new(),
+            related_information: Vec::new(),
+            fixes: Vec::new(),
+        }
+    }
+
+    pub fn with_fixes(mut self, fixes: Vec<Fix>) -> Self {
+        self.fixes = fixes;
+        self
+    }
+
+    pub fn with_secondary_labels(mut self, secondary_labels: Vec<Label>) -> Self {
+        self.secondary_labels = secondary_labels;
+        self
+    }
+
+    pub fn with_related_information(
+        mut self,
+        related_information: Vec<RelatedInformation>,
+    ) -> Self {
+        self.related_information = related_information;
+        self
+    }
+}
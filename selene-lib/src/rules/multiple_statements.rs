@@ -0,0 +1,28 @@
+use std::convert::Infallible;
+
+use full_moon::ast::Ast;
+use serde::Deserialize;
+
+use crate::rules::{AstContext, Category, Context, Diagnostic, Rule, Severity};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MultipleStatementsConfig {}
+
+#[derive(Debug, Default)]
+pub struct MultipleStatementsLint;
+
+impl Rule for MultipleStatementsLint {
+    type Config = MultipleStatementsConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Warning;
+    const CATEGORY: Category = Category::Style;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(MultipleStatementsLint)
+    }
+
+    fn pass(&self, _ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,123 @@
+use std::convert::Infallible;
+
+use full_moon::ast::{Ast, Block, Expression, Stmt};
+use serde::Deserialize;
+
+use crate::{
+    ast_util,
+    rules::{Applicability, AstContext, Category, Context, Diagnostic, Fix, Label, Rule, Severity},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ParentheseConditionsConfig {}
+
+#[derive(Debug, Default)]
+pub struct ParentheseConditionsLint;
+
+impl Rule for ParentheseConditionsLint {
+    type Config = ParentheseConditionsConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Warning;
+    const CATEGORY: Category = Category::Style;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(ParentheseConditionsLint)
+    }
+
+    fn pass(&self, ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        walk_block(ast.nodes(), &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn walk_block(block: &Block, diagnostics: &mut Vec<Diagnostic>) {
+    for stmt in block.stmts() {
+        match stmt {
+            Stmt::If(if_stmt) => {
+                check_condition(if_stmt.condition(), diagnostics);
+                walk_block(if_stmt.block(), diagnostics);
+
+                if let Some(else_ifs) = if_stmt.else_if() {
+                    for else_if in else_ifs {
+                        check_condition(else_if.condition(), diagnostics);
+                        walk_block(else_if.block(), diagnostics);
+                    }
+                }
+
+                if let Some(else_block) = if_stmt.else_block() {
+                    walk_block(else_block, diagnostics);
+                }
+            }
+
+            Stmt::While(while_stmt) => {
+                check_condition(while_stmt.condition(), diagnostics);
+                walk_block(while_stmt.block(), diagnostics);
+            }
+
+            Stmt::Repeat(repeat_stmt) => {
+                check_condition(repeat_stmt.until(), diagnostics);
+                walk_block(repeat_stmt.block(), diagnostics);
+            }
+
+            Stmt::Do(do_stmt) => walk_block(do_stmt.block(), diagnostics),
+            Stmt::NumericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::GenericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::FunctionDeclaration(function) => walk_block(function.body().block(), diagnostics),
+            Stmt::LocalFunction(function) => walk_block(function.body().block(), diagnostics),
+
+            _ => {}
+        }
+    }
+}
+
+fn check_condition(condition: &Expression, diagnostics: &mut Vec<Diagnostic>) {
+    let Expression::Parentheses { expression, .. } = condition else {
+        return;
+    };
+
+    let (start, end) = ast_util::range(condition);
+    let inner = expression.to_string();
+    let inner = inner.trim();
+
+    diagnostics.push(
+        Diagnostic::new(
+            "parenthese_conditions",
+            "conditions don't need to be wrapped in parentheses".to_owned(),
+            Label::new((start, end)),
+        )
+        .with_fixes(vec![Fix {
+            range: (start, end),
+            replacement: inner.to_owned(),
+            applicability: Applicability::MachineApplicable,
+            label: "remove the redundant parentheses".to_owned(),
+        }]),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(source: &str) -> Vec<Diagnostic> {
+        let ast = full_moon::parse(source).expect("valid lua");
+        ParentheseConditionsLint.pass(
+            &ast,
+            &Context::default(),
+            &AstContext::from_ast(&ast),
+        )
+    }
+
+    #[test]
+    fn flags_a_parenthesized_if_condition() {
+        let diagnostics = diagnostics("if (x) then end");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fixes[0].replacement, "x");
+    }
+
+    #[test]
+    fn allows_an_unparenthesized_condition() {
+        assert!(diagnostics("if x then end").is_empty());
+    }
+}
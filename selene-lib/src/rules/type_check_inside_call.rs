@@ -0,0 +1,191 @@
+use std::convert::Infallible;
+
+use full_moon::ast::{
+    Ast, BinOp, Block, Call, Expression, FunctionArgs, FunctionCall, LastStmt, Prefix, Stmt, Suffix,
+};
+use serde::Deserialize;
+
+use crate::{
+    ast_util,
+    rules::{Applicability, AstContext, Category, Context, Diagnostic, Fix, Label, Rule, Severity},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct TypeCheckInsideCallConfig {}
+
+#[derive(Debug, Default)]
+pub struct TypeCheckInsideCallLint;
+
+impl Rule for TypeCheckInsideCallLint {
+    type Config = TypeCheckInsideCallConfig;
+    type Error = Infallible;
+
+    const SEVERITY: Severity = Severity::Error;
+    const CATEGORY: Category = Category::Correctness;
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(TypeCheckInsideCallLint)
+    }
+
+    fn pass(&self, ast: &Ast, _context: &Context, _ast_context: &AstContext) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        walk_block(ast.nodes(), &mut diagnostics);
+        diagnostics
+    }
+}
+
+fn walk_block(block: &Block, diagnostics: &mut Vec<Diagnostic>) {
+    for stmt in block.stmts() {
+        match stmt {
+            Stmt::Assignment(assignment) => {
+                for expr in assignment.expressions() {
+                    check_expression(expr, diagnostics);
+                }
+            }
+
+            Stmt::LocalAssignment(local) => {
+                for expr in local.expressions() {
+                    check_expression(expr, diagnostics);
+                }
+            }
+
+            Stmt::If(if_stmt) => {
+                check_expression(if_stmt.condition(), diagnostics);
+                walk_block(if_stmt.block(), diagnostics);
+
+                if let Some(else_ifs) = if_stmt.else_if() {
+                    for else_if in else_ifs {
+                        check_expression(else_if.condition(), diagnostics);
+                        walk_block(else_if.block(), diagnostics);
+                    }
+                }
+
+                if let Some(else_block) = if_stmt.else_block() {
+                    walk_block(else_block, diagnostics);
+                }
+            }
+
+            Stmt::While(while_stmt) => {
+                check_expression(while_stmt.condition(), diagnostics);
+                walk_block(while_stmt.block(), diagnostics);
+            }
+
+            Stmt::Repeat(repeat_stmt) => {
+                check_expression(repeat_stmt.until(), diagnostics);
+                walk_block(repeat_stmt.block(), diagnostics);
+            }
+
+            Stmt::Do(do_stmt) => walk_block(do_stmt.block(), diagnostics),
+            Stmt::NumericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::GenericFor(for_stmt) => walk_block(for_stmt.block(), diagnostics),
+            Stmt::FunctionDeclaration(function) => walk_block(function.body().block(), diagnostics),
+            Stmt::LocalFunction(function) => walk_block(function.body().block(), diagnostics),
+
+            _ => {}
+        }
+    }
+
+    if let Some(LastStmt::Return(return_stmt)) = block.last_stmt() {
+        for expr in return_stmt.returns() {
+            check_expression(expr, diagnostics);
+        }
+    }
+}
+
+fn check_expression(expression: &Expression, diagnostics: &mut Vec<Diagnostic>) {
+    match expression {
+        Expression::Parentheses { expression, .. } => check_expression(expression, diagnostics),
+        Expression::UnaryOperator { expression, .. } => check_expression(expression, diagnostics),
+        Expression::BinaryOperator { lhs, rhs, .. } => {
+            check_expression(lhs, diagnostics);
+            check_expression(rhs, diagnostics);
+        }
+        Expression::FunctionCall(call) => check_type_call(call, diagnostics),
+        _ => {}
+    }
+}
+
+fn check_type_call(call: &FunctionCall, diagnostics: &mut Vec<Diagnostic>) {
+    let Prefix::Name(name) = call.prefix() else {
+        return;
+    };
+
+    if name.to_string().trim() != "type" {
+        return;
+    }
+
+    let mut suffixes = call.suffixes();
+
+    let Some(Suffix::Call(Call::AnonymousCall(FunctionArgs::Parentheses { arguments, .. }))) =
+        suffixes.next()
+    else {
+        return;
+    };
+
+    // Anything past the call itself (e.g. `type(x == "string").foo`) is out
+    // of scope for this rewrite.
+    if suffixes.next().is_some() {
+        return;
+    }
+
+    if arguments.len() != 1 {
+        return;
+    }
+
+    let Some(Expression::BinaryOperator { lhs, binop, rhs }) = arguments.iter().next() else {
+        return;
+    };
+
+    if !matches!(binop, BinOp::TwoEqual(_) | BinOp::TildeEqual(_)) {
+        return;
+    }
+
+    let (start, end) = ast_util::range(call);
+
+    let replacement = format!(
+        "type({}) {} {}",
+        lhs.to_string().trim(),
+        binop.to_string().trim(),
+        rhs.to_string().trim(),
+    );
+
+    diagnostics.push(
+        Diagnostic::new(
+            "type_check_inside_call",
+            "comparison is inside the `type()` call instead of around it".to_owned(),
+            Label::new((start, end)),
+        )
+        .with_fixes(vec![Fix {
+            range: (start, end),
+            replacement,
+            applicability: Applicability::MachineApplicable,
+            label: "move the comparison outside of `type()`".to_owned(),
+        }]),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(source: &str) -> Vec<Diagnostic> {
+        let ast = full_moon::parse(source).expect("valid lua");
+        TypeCheckInsideCallLint.pass(
+            &ast,
+            &Context::default(),
+            &AstContext::from_ast(&ast),
+        )
+    }
+
+    #[test]
+    fn flags_a_comparison_inside_the_type_call() {
+        let diagnostics = diagnostics(r#"local a = type(x == "string")"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].fixes[0].replacement, r#"type(x) == "string""#);
+    }
+
+    #[test]
+    fn allows_the_comparison_outside_the_type_call() {
+        assert!(diagnostics(r#"local a = type(x) == "string""#).is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+/// The standard library a linted file is checked against, identified by its
+/// `std` name (e.g. `"lua51"`, `"roblox"`). Holds only that name: no
+/// globals or fields are loaded, and inline TOML definitions are not read.
+#[derive(Clone, Debug, Default)]
+pub struct StandardLibrary {
+    pub name: Option<String>,
+}
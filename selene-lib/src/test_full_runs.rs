@@ -0,0 +1,27 @@
+//! End-to-end smoke tests: run every built-in rule together against a
+//! snippet, rather than one rule in isolation like the per-rule unit tests.
+
+use crate::test_util::lint;
+
+#[test]
+fn lints_a_clean_file_without_diagnostics() {
+    assert!(lint("local a = 1\nprint(a)\n").is_empty());
+}
+
+#[test]
+fn surfaces_diagnostics_from_more_than_one_rule_in_a_single_pass() {
+    let diagnostics = lint(
+        r#"
+        local a = "hello\qworld"
+        if (a) then end
+        "#,
+    );
+
+    let codes: Vec<&str> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.diagnostic.code.as_str())
+        .collect();
+
+    assert!(codes.contains(&"bad_string_escape"));
+    assert!(codes.contains(&"parenthese_conditions"));
+}
@@ -0,0 +1,13 @@
+//! Shared helper for `test_full_runs`: build a `Checker` wired up with every
+//! built-in rule at its default severity, and run it against a snippet.
+
+use crate::{standard_library::StandardLibrary, Checker, CheckerConfig, CheckerDiagnostic};
+
+pub fn lint(source: &str) -> Vec<CheckerDiagnostic> {
+    let checker: Checker<toml::Value> = Checker::new(CheckerConfig::default(), StandardLibrary::default())
+        .expect("default config is always valid");
+
+    let ast = full_moon::parse(source).expect("valid lua");
+
+    checker.test_on(&ast)
+}
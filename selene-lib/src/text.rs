@@ -0,0 +1,53 @@
+/// Applies a set of `(start, end, replacement)` byte-range edits to `source`
+/// in a single pass. Edits must already be sorted by `start` and
+/// non-overlapping — [`crate::Checker::fix_on`] guarantees both before
+/// calling this — since each replacement is anchored to the *original*
+/// offsets, not the offsets of the text built up so far.
+pub fn splice(source: &str, edits: &[(u32, u32, String)]) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut cursor = 0usize;
+
+    for (start, end, replacement) in edits {
+        let start = *start as usize;
+        let end = *end as usize;
+
+        output.push_str(&source[cursor..start]);
+        output.push_str(replacement);
+        cursor = end;
+    }
+
+    output.push_str(&source[cursor..]);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splice_applies_edits_against_original_offsets() {
+        let source = "local a = b";
+        let edits = vec![(6u32, 7u32, "renamed".to_owned())];
+
+        assert_eq!(splice(source, &edits), "local renamed = b");
+    }
+
+    #[test]
+    fn splice_with_no_edits_returns_source_unchanged() {
+        let source = "local a = b";
+
+        assert_eq!(splice(source, &[]), source);
+    }
+
+    #[test]
+    fn splice_applies_multiple_edits_in_one_pass() {
+        let source = "a = b\nb = a\n";
+        let edits = vec![
+            (0u32, 5u32, "a, b = b, a".to_owned()),
+            (6u32, 11u32, "".to_owned()),
+        ];
+
+        assert_eq!(splice(source, &edits), "a, b = b, a\n\n");
+    }
+}